@@ -278,6 +278,12 @@ pub fn alloc_from_iter<I: IntoIterator<Item = T>>(&self, iter: I) -> &mut [T] {
         iter.alloc_from_iter(self)
     }
 
+    /// Returns the number of bytes reserved by the chunks of this arena, whether or not they
+    /// have been handed out yet.
+    pub fn allocated_bytes(&self) -> usize {
+        self.chunks.borrow().iter().map(|chunk| chunk.storage.len() * mem::size_of::<T>()).sum()
+    }
+
     /// Grows the arena.
     #[inline(never)]
     #[cold]
@@ -414,6 +420,12 @@ fn default() -> DroplessArena {
 }
 
 impl DroplessArena {
+    /// Returns the number of bytes reserved by the chunks of this arena, whether or not they
+    /// have been handed out yet.
+    pub fn allocated_bytes(&self) -> usize {
+        self.chunks.borrow().iter().map(|chunk| chunk.storage.len()).sum()
+    }
+
     fn grow(&self, layout: Layout) {
         // Add some padding so we can align `self.end` while
         // stilling fitting in a `layout` allocation.
@@ -718,6 +730,19 @@ pub fn alloc_from_iter<'a, T: ArenaAllocatable<'tcx, C>, C>(
         ) -> &'a mut [T] {
             T::allocate_from_iter(self, iter)
         }
+
+        /// Returns the number of bytes reserved by each of the arenas, starting with the
+        /// dropless one. Typed arenas that never allocated are skipped.
+        pub fn allocated_bytes(&self) -> ::std::vec::Vec<(&'static str, usize)> {
+            let mut stats = ::std::vec![("dropless", self.dropless.allocated_bytes())];
+            $(
+                let bytes = self.$name.allocated_bytes();
+                if bytes > 0 {
+                    stats.push((stringify!($name), bytes));
+                }
+            )*
+            stats
+        }
     }
 }
 
@@ -986,6 +986,7 @@ fn determine_cgu_reuse<'tcx>(tcx: TyCtxt<'tcx>, cgu: &CodegenUnit<'tcx>) -> CguR
     if tcx.dep_graph.previous_work_product(work_product_id).is_none() {
         // We don't have anything cached for this CGU. This can happen
         // if the CGU did not exist in the previous session.
+        report_cgu_reuse(tcx, cgu, CguReuse::No, "not present in the previous session");
         return CguReuse::No;
     }
 
@@ -1006,7 +1007,7 @@ fn determine_cgu_reuse<'tcx>(tcx: TyCtxt<'tcx>, cgu: &CodegenUnit<'tcx>) -> CguR
         // We can re-use either the pre- or the post-thinlto state. If no LTO is
         // being performed then we can use post-LTO artifacts, otherwise we must
         // reuse pre-LTO artifacts
-        match compute_per_cgu_lto_type(
+        let reuse = match compute_per_cgu_lto_type(
             &tcx.sess.lto(),
             &tcx.sess.opts,
             tcx.crate_types(),
@@ -1014,8 +1015,25 @@ fn determine_cgu_reuse<'tcx>(tcx: TyCtxt<'tcx>, cgu: &CodegenUnit<'tcx>) -> CguR
         ) {
             ComputedLtoType::No => CguReuse::PostLto,
             _ => CguReuse::PreLto,
-        }
+        };
+        report_cgu_reuse(tcx, cgu, reuse, "all of its inputs are unchanged");
+        reuse
     } else {
+        report_cgu_reuse(tcx, cgu, CguReuse::No, "some of its inputs have changed");
+        if tcx.sess.opts.unstable_opts.incremental_info {
+            let mut label = "first dependency that is not green:";
+            for dep_node in tcx.dep_graph.red_dependency_chain(tcx, &dep_node) {
+                eprintln!("[incremental]     {label} {dep_node:?}");
+                label = "which is not green because of:";
+            }
+        }
         CguReuse::No
     }
 }
+
+/// Explains the reuse decision for `cgu` when `-Zincremental-info` is enabled.
+fn report_cgu_reuse(tcx: TyCtxt<'_>, cgu: &CodegenUnit<'_>, reuse: CguReuse, reason: &str) {
+    if tcx.sess.opts.unstable_opts.incremental_info {
+        eprintln!("[incremental] codegen unit `{}` reuse: {reuse}, {reason}", cgu.name());
+    }
+}
@@ -696,27 +696,32 @@ fn draw_line(
     ) {
         // Tabs are assumed to have been replaced by spaces in calling code.
         debug_assert!(!source_string.contains('\t'));
-        let line_len = source_string.len();
+        let line_len = str_width(source_string);
         // Create the source line we will highlight.
         let left = margin.left(line_len);
         let right = margin.right(line_len);
-        // On long lines, we strip the source line, accounting for unicode.
-        let mut taken = 0;
-        let code: String = source_string
-            .chars()
-            .skip(left)
-            .take_while(|ch| {
-                // Make sure that the trimming on the right will fall within the terminal width.
-                // FIXME: `unicode_width` sometimes disagrees with terminals on how wide a `char` is.
-                // For now, just accept that sometimes the code line will be longer than desired.
-                let next = unicode_width::UnicodeWidthChar::width(*ch).unwrap_or(1);
-                if taken + next > right - left {
-                    return false;
-                }
-                taken += next;
-                true
-            })
-            .collect();
+        // `left` and `right` are display columns, while the buffer holds one `char` per cell, so
+        // cut the line by the width of its `char`s. When the start of the line is cut, its first
+        // three columns are blanked out for the `...` marker. A wide `char` straddling the start
+        // is replaced by spaces, so that the code stays aligned with the annotations below it.
+        // FIXME: `unicode_width` sometimes disagrees with terminals on how wide a `char` is.
+        // For now, just accept that sometimes the code line will be longer than desired.
+        let start = if margin.was_cut_left() { left + 3 } else { left };
+        let mut code = " ".repeat(start - left);
+        let mut col = 0;
+        for ch in source_string.chars() {
+            let width = char_width(ch);
+            // Make sure that the trimming on the right will fall within the terminal width.
+            if col + width > right {
+                break;
+            }
+            if col >= start {
+                code.push(ch);
+            } else if col + width > start {
+                code.push_str(&" ".repeat(col + width - start));
+            }
+            col += width;
+        }
         buffer.puts(line_offset, code_offset, &code, Style::Quotation);
         if margin.was_cut_left() {
             // We have stripped some code/whitespace from the beginning, make it clear.
@@ -724,7 +729,10 @@ fn draw_line(
         }
         if margin.was_cut_right(line_len) {
             // We have stripped some code after the right-most span end, make it clear we did so.
-            buffer.puts(line_offset, code_offset + taken - 3, "...", Style::LineNumber);
+            // The buffer is indexed by `char`, not by column, so place the marker over the last
+            // three `char`s that were taken.
+            let last = code.chars().count().saturating_sub(3);
+            buffer.puts(line_offset, code_offset + last, "...", Style::LineNumber);
         }
         buffer.puts(line_offset, 0, &self.maybe_anonymized(line_index), Style::LineNumber);
 
@@ -768,14 +776,7 @@ fn render_source_line(
         let line_offset = buffer.num_lines();
 
         // Left trim
-        let left = margin.left(source_string.len());
-
-        // Account for unicode characters of width !=0 that were removed.
-        let left = source_string
-            .chars()
-            .take(left)
-            .map(|ch| unicode_width::UnicodeWidthChar::width(ch).unwrap_or(1))
-            .sum();
+        let left = margin.left(str_width(&source_string));
 
         self.draw_line(
             buffer,
@@ -958,7 +959,13 @@ fn render_source_line(
             annotations_position.push((p, annotation));
             for (j, next) in annotations.iter().enumerate() {
                 if j > i {
-                    let l = next.label.as_ref().map_or(0, |label| label.len() + 2);
+                    let l = next.label.as_ref().map_or(0, |label| str_width(label) + 2);
+                    // The buffer holds one `char` per cell, so a label that is wider or narrower
+                    // than its number of `char`s would shift whatever is drawn to its right.
+                    let misaligns = next
+                        .label
+                        .as_ref()
+                        .is_some_and(|label| str_width(label) != label.chars().count());
                     if (overlaps(next, annotation, l) // Do not allow two labels to be in the same
                                                      // line if they overlap including padding, to
                                                      // avoid situations like:
@@ -977,6 +984,7 @@ fn render_source_line(
                         || (annotation.has_label()   // so as not to overlap the horizontal lines.
                             && next.takes_space())
                         || (annotation.takes_space() && next.takes_space())
+                        || misaligns
                         || (overlaps(next, annotation, l)
                             && next.end_col <= annotation.end_col
                             && next.has_label()
@@ -1573,13 +1581,16 @@ fn emit_message_default(
                 for line in &annotated_file.lines {
                     max_line_len = max(
                         max_line_len,
-                        annotated_file.file.get_line(line.line_index - 1).map_or(0, |s| s.len()),
+                        annotated_file
+                            .file
+                            .get_line(line.line_index - 1)
+                            .map_or(0, |s| str_width(&s)),
                     );
                     for ann in &line.annotations {
                         span_right_margin = max(span_right_margin, ann.start_col.display);
                         span_right_margin = max(span_right_margin, ann.end_col.display);
                         // FIXME: account for labels not in the same line
-                        let label_right = ann.label.as_ref().map_or(0, |l| l.len() + 1);
+                        let label_right = ann.label.as_ref().map_or(0, |l| str_width(l) + 1);
                         label_right_margin =
                             max(label_right_margin, ann.end_col.display + label_right);
                     }
@@ -1979,11 +1990,11 @@ fn emit_suggestion_default(
                     };
                     // ...or trailing spaces. Account for substitutions containing unicode
                     // characters.
-                    let sub_len: usize =
-                        if is_whitespace_addition { &part.snippet } else { part.snippet.trim() }
-                            .chars()
-                            .map(|ch| unicode_width::UnicodeWidthChar::width(ch).unwrap_or(1))
-                            .sum();
+                    let sub_len: usize = str_width(if is_whitespace_addition {
+                        &part.snippet
+                    } else {
+                        part.snippet.trim()
+                    });
 
                     let offset: isize = offsets
                         .iter()
@@ -2019,11 +2030,7 @@ fn emit_suggestion_default(
                     }
 
                     // length of the code after substitution
-                    let full_sub_len = part
-                        .snippet
-                        .chars()
-                        .map(|ch| unicode_width::UnicodeWidthChar::width(ch).unwrap_or(1))
-                        .sum::<usize>() as isize;
+                    let full_sub_len = str_width(&part.snippet) as isize;
 
                     // length of the code to be substituted
                     let snippet_len = span_end_pos as isize - span_start_pos as isize;
@@ -2490,6 +2497,18 @@ fn num_decimal_digits(num: usize) -> usize {
     ('\u{2069}', ""),
 ];
 
+/// Returns the number of terminal columns `s` occupies, accounting for wide and zero-width
+/// characters.
+fn str_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+/// Returns the number of terminal columns `ch` occupies. Control characters, for which
+/// `unicode_width` has no answer, count as one column.
+fn char_width(ch: char) -> usize {
+    unicode_width::UnicodeWidthChar::width(ch).unwrap_or(1)
+}
+
 fn normalize_whitespace(str: &str) -> String {
     let mut s = str.to_string();
     for (c, replacement) in OUTPUT_REPLACEMENTS {
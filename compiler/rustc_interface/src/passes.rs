@@ -731,6 +731,7 @@ pub fn create_global_ctxt<'tcx>(
                     extern_providers,
                     query_result_on_disk_cache,
                     incremental,
+                    sess.opts.unstable_opts.query_stats,
                 ),
             )
         })
@@ -977,6 +978,10 @@ pub fn start_codegen<'tcx>(
 
     info!("Post-codegen\n{:?}", tcx.debug_stats());
 
+    if tcx.sess.opts.unstable_opts.print_arena_stats {
+        tcx.print_arena_stats();
+    }
+
     if tcx.sess.opts.output_types.contains_key(&OutputType::Mir) {
         if let Err(error) = rustc_mir_transform::dump_mir::emit_mir(tcx) {
             tcx.sess.emit_err(errors::CantEmitMIR { error });
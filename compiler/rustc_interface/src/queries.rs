@@ -382,6 +382,10 @@ pub fn enter<F, T>(&self, f: F) -> T
                 gcx.enter(rustc_query_impl::alloc_self_profile_query_strings);
             }
 
+            if self.session().opts.unstable_opts.query_stats {
+                gcx.enter(rustc_query_impl::print_query_stats);
+            }
+
             self.session()
                 .time("serialize_dep_graph", || gcx.enter(rustc_incremental::save_dep_graph));
         }
@@ -203,6 +203,15 @@ lint_dropping_references = calls to `std::mem::drop` with a reference instead of
     .label = argument has type `{$arg_ty}`
     .note = use `let _ = ...` to ignore the expression or result
 
+lint_duplicate_lifetime_bounds = {$count ->
+        [one] duplicate lifetime bound
+        *[other] duplicate lifetime bounds
+    }
+    .suggestion = remove {$count ->
+        [one] this bound
+        *[other] these bounds
+    }
+
 lint_enum_intrinsics_mem_discriminant =
     the return value of `mem::discriminant` is unspecified when called with a non-enum type
     .note = the argument to `discriminant` should be a reference to an enum, but it was passed a reference to a `{$ty_param}`, which is not an enum.
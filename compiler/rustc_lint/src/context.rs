@@ -966,6 +966,14 @@ fn lookup_with_diagnostics(
                         Applicability::MachineApplicable
                     );
                 }
+                BuiltinLintDiagnostics::UnusedLabel { removal_span } => {
+                    db.span_suggestion_verbose(
+                        removal_span,
+                        "remove this label",
+                        "",
+                        Applicability::MachineApplicable
+                    );
+                }
             }
             // Rewrap `db`, and pass control to the user.
             decorate(db)
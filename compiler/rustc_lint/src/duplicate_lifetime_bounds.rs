@@ -0,0 +1,160 @@
+use crate::{
+    lints::{DuplicateLifetimeBoundsDiag, DuplicateLifetimeBoundsSuggestion},
+    EarlyContext, EarlyLintPass, LintContext,
+};
+use rustc_ast::{GenericBound, GenericBounds, GenericParamKind, Generics, WherePredicate};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_span::{symbol::Symbol, Span};
+use std::ops::Range;
+
+declare_lint! {
+    /// The `duplicate_lifetime_bounds` lint detects lifetime bounds that are
+    /// stated more than once for the same lifetime parameter.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// fn foo<'a: 'b + 'b, 'b>(x: &'a u8) -> &'b u8 {
+    ///     x
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Repeating an outlives bound, either in the same list of bounds or in
+    /// a separate `where` clause, has no effect. The duplicate may be removed
+    /// to avoid confusion.
+    pub DUPLICATE_LIFETIME_BOUNDS,
+    Warn,
+    "detects lifetime bounds that are stated more than once"
+}
+
+declare_lint_pass!(DuplicateLifetimeBounds => [DUPLICATE_LIFETIME_BOUNDS]);
+
+impl EarlyLintPass for DuplicateLifetimeBounds {
+    fn check_generics(&mut self, cx: &EarlyContext<'_>, generics: &Generics) {
+        if generics.span.from_expansion() {
+            return;
+        }
+
+        // Every `'a: 'b` requirement seen so far, in source order.
+        let mut seen = FxHashSet::default();
+
+        for param in &generics.params {
+            let GenericParamKind::Lifetime = param.kind else { continue };
+            let duplicates = find_duplicates(&mut seen, param.ident.name, &param.bounds);
+            // The first bound of a parameter can only repeat an earlier one when the name of
+            // the parameter itself is repeated, which is already an error.
+            if duplicates.is_empty() || duplicates[0] == 0 {
+                continue;
+            }
+            let spans = duplicate_bound_spans(&param.bounds, &duplicates);
+            lint_duplicates(cx, duplicates.len(), spans);
+        }
+
+        // The removals for the `where` clause are all computed before linting, so that
+        // neighbouring predicates made up only of duplicates can share a single removal
+        // instead of each claiming the comma between them.
+        let predicates = &generics.where_clause.predicates;
+        let mut removals: Vec<(usize, Removal)> = Vec::new();
+        for (i, predicate) in predicates.iter().enumerate() {
+            let WherePredicate::RegionPredicate(region_predicate) = predicate else { continue };
+            let bounds = &region_predicate.bounds;
+            let duplicates =
+                find_duplicates(&mut seen, region_predicate.lifetime.ident.name, bounds);
+            if duplicates.is_empty() {
+                continue;
+            }
+            if duplicates.len() < bounds.len() {
+                let spans = duplicate_bound_spans(bounds, &duplicates);
+                removals.push((duplicates.len(), Removal::Bounds(spans)));
+            } else if let Some((count, Removal::Predicates(run))) = removals.last_mut()
+                && run.end == i
+            {
+                *count += duplicates.len();
+                run.end = i + 1;
+            } else {
+                removals.push((duplicates.len(), Removal::Predicates(i..i + 1)));
+            }
+        }
+
+        for (count, removal) in removals {
+            let spans = match removal {
+                Removal::Bounds(spans) => spans,
+                // Remove the predicates along with the comma separating them from their
+                // neighbours, or the entire `where` clause if nothing else is left in it.
+                Removal::Predicates(run) => vec![if run.len() == predicates.len() {
+                    generics.where_clause.span
+                } else if run.start > 0 {
+                    predicates[run.start - 1]
+                        .span()
+                        .shrink_to_hi()
+                        .to(predicates[run.end - 1].span())
+                } else {
+                    predicates[0].span().until(predicates[run.end].span())
+                }],
+            };
+            lint_duplicates(cx, count, spans);
+        }
+    }
+}
+
+/// What to remove from a `where` clause for one lint.
+enum Removal {
+    /// Some of the bounds of a single predicate.
+    Bounds(Vec<Span>),
+    /// A run of neighbouring predicates whose bounds are all duplicates.
+    Predicates(Range<usize>),
+}
+
+/// Returns the indices of the lifetime bounds in `bounds` that repeat a requirement on
+/// `lifetime` already present in `seen`, recording the new ones along the way.
+fn find_duplicates(
+    seen: &mut FxHashSet<(Symbol, Symbol)>,
+    lifetime: Symbol,
+    bounds: &GenericBounds,
+) -> Vec<usize> {
+    bounds
+        .iter()
+        .enumerate()
+        .filter_map(|(i, bound)| {
+            let GenericBound::Outlives(outlived) = bound else { return None };
+            (!seen.insert((lifetime, outlived.ident.name))).then_some(i)
+        })
+        .collect()
+}
+
+/// Returns the spans removing the `duplicates` from `bounds`, which must keep at least one
+/// bound, each together with one of the `+` next to it.
+fn duplicate_bound_spans(bounds: &GenericBounds, duplicates: &[usize]) -> Vec<Span> {
+    duplicates
+        .iter()
+        .map(|&i| {
+            // Eat the `+` before the bound, unless only duplicates precede it, in which
+            // case eat the `+` after it so the removals do not overlap.
+            if (0..i).all(|j| duplicates.contains(&j)) {
+                bounds[i].span().until(bounds[i + 1].span())
+            } else {
+                bounds[i - 1].span().shrink_to_hi().to(bounds[i].span())
+            }
+        })
+        .collect()
+}
+
+/// Emits the lint for `count` duplicate bounds, suggesting to remove `spans`.
+fn lint_duplicates(cx: &EarlyContext<'_>, count: usize, spans: Vec<Span>) {
+    cx.emit_spanned_lint(
+        DUPLICATE_LIFETIME_BOUNDS,
+        spans.clone(),
+        DuplicateLifetimeBoundsDiag {
+            count,
+            suggestion: DuplicateLifetimeBoundsSuggestion {
+                spans,
+                applicability: Applicability::MachineApplicable,
+            },
+        },
+    );
+}
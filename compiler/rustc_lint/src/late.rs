@@ -250,8 +250,10 @@ fn visit_arm(&mut self, a: &'tcx hir::Arm<'tcx>) {
     }
 
     fn visit_generic_param(&mut self, p: &'tcx hir::GenericParam<'tcx>) {
-        lint_callback!(self, check_generic_param, p);
-        hir_visit::walk_generic_param(self, p);
+        self.with_lint_attrs(p.hir_id, |cx| {
+            lint_callback!(cx, check_generic_param, p);
+            hir_visit::walk_generic_param(cx, p);
+        })
     }
 
     fn visit_generics(&mut self, g: &'tcx hir::Generics<'tcx>) {
@@ -351,6 +351,11 @@ fn visit_arm(&mut self, a: &'tcx hir::Arm<'tcx>) {
         intravisit::walk_arm(self, a);
     }
 
+    fn visit_generic_param(&mut self, p: &'tcx hir::GenericParam<'tcx>) {
+        self.add_id(p.hir_id);
+        intravisit::walk_generic_param(self, p);
+    }
+
     fn visit_trait_item(&mut self, trait_item: &'tcx hir::TraitItem<'tcx>) {
         self.add_id(trait_item.hir_id());
         intravisit::walk_trait_item(self, trait_item);
@@ -425,6 +430,11 @@ fn visit_arm(&mut self, a: &'tcx hir::Arm<'tcx>) {
         intravisit::walk_arm(self, a);
     }
 
+    fn visit_generic_param(&mut self, p: &'tcx hir::GenericParam<'tcx>) {
+        self.add_id(p.hir_id);
+        intravisit::walk_generic_param(self, p);
+    }
+
     fn visit_trait_item(&mut self, trait_item: &'tcx hir::TraitItem<'tcx>) {
         self.add_id(trait_item.hir_id());
         intravisit::walk_trait_item(self, trait_item);
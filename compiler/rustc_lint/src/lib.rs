@@ -54,6 +54,7 @@
 mod context;
 mod deref_into_dyn_supertrait;
 mod drop_forget_useless;
+mod duplicate_lifetime_bounds;
 mod early;
 mod enum_intrinsics_non_enums;
 mod errors;
@@ -103,6 +104,7 @@
 use builtin::*;
 use deref_into_dyn_supertrait::*;
 use drop_forget_useless::*;
+use duplicate_lifetime_bounds::*;
 use enum_intrinsics_non_enums::EnumIntrinsicsNonEnums;
 use for_loops_over_fallibles::*;
 use hidden_unicode_codepoints::*;
@@ -178,6 +180,7 @@ fn lint_mod(tcx: TyCtxt<'_>, module_def_id: LocalModDefId) {
             HiddenUnicodeCodepoints: HiddenUnicodeCodepoints,
             IncompleteInternalFeatures: IncompleteInternalFeatures,
             RedundantSemicolons: RedundantSemicolons,
+            DuplicateLifetimeBounds: DuplicateLifetimeBounds,
             UnusedDocComment: UnusedDocComment,
             UnexpectedCfgs: UnexpectedCfgs,
         ]
@@ -294,6 +297,7 @@ macro_rules! add_lint_group {
         UNUSED_DOC_COMMENTS,
         UNUSED_EXTERN_CRATES,
         UNUSED_FEATURES,
+        UNUSED_HIGHER_RANKED_LIFETIMES,
         UNUSED_LABELS,
         UNUSED_PARENS,
         UNUSED_BRACES,
@@ -741,6 +741,24 @@ pub struct UndroppedManuallyDropsSuggestion {
     pub end_span: Span,
 }
 
+// duplicate_lifetime_bounds.rs
+#[derive(LintDiagnostic)]
+#[diag(lint_duplicate_lifetime_bounds)]
+pub struct DuplicateLifetimeBoundsDiag {
+    pub count: usize,
+    #[subdiagnostic]
+    pub suggestion: DuplicateLifetimeBoundsSuggestion,
+}
+
+#[derive(Subdiagnostic)]
+#[multipart_suggestion(lint_suggestion)]
+pub struct DuplicateLifetimeBoundsSuggestion {
+    #[suggestion_part(code = "")]
+    pub spans: Vec<Span>,
+    #[applicability]
+    pub applicability: Applicability,
+}
+
 // invalid_from_utf8.rs
 #[derive(LintDiagnostic)]
 pub enum InvalidFromUtf8Diag {
@@ -1620,6 +1620,30 @@
     "detects lifetime parameters that are never used"
 }
 
+declare_lint! {
+    /// The `unused_higher_ranked_lifetimes` lint detects lifetimes introduced
+    /// by the `for<>` binder of a trait bound that are never used.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// fn call<F: for<'a> Fn()>(f: F) {
+    ///     f()
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// A lifetime that is bound by `for<'a>` but does not appear in the
+    /// trait reference has no effect, and the binder is likely left over
+    /// from an earlier version of the bound. Consider removing it.
+    pub UNUSED_HIGHER_RANKED_LIFETIMES,
+    Warn,
+    "detects lifetimes in `for<>` binders of trait bounds that are never used"
+}
+
 declare_lint! {
     /// The `tyvar_behind_raw_pointer` lint detects raw pointer to an
     /// inference variable.
@@ -3457,6 +3481,7 @@
         UNUSED_CRATE_DEPENDENCIES,
         UNUSED_EXTERN_CRATES,
         UNUSED_FEATURES,
+        UNUSED_HIGHER_RANKED_LIFETIMES,
         UNUSED_IMPORTS,
         UNUSED_LABELS,
         UNUSED_LIFETIMES,
@@ -572,6 +572,10 @@ pub enum BuiltinLintDiagnostics {
         /// The span of the unnecessarily-qualified path to remove.
         removal_span: Span,
     },
+    UnusedLabel {
+        /// The span of the label and its trailing colon to remove.
+        removal_span: Span,
+    },
 }
 
 /// Lints that are buffered up early on in the `Session` before the
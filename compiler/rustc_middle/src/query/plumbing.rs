@@ -83,6 +83,10 @@ pub struct QuerySystem<'tcx> {
     pub fns: QuerySystemFns<'tcx>,
 
     pub jobs: AtomicU64,
+
+    /// Whether cache hits and misses are counted for `-Z query-stats`. This is read from the
+    /// session once, so that a lookup only pays for a check of this flag.
+    pub query_stats: bool,
 }
 
 #[derive(Copy, Clone)]
@@ -151,8 +155,18 @@ pub fn query_get_at<'tcx, Cache>(
 {
     let key = key.into_query_param();
     match try_get_cached(tcx, query_cache, &key) {
-        Some(value) => value,
-        None => execute_query(tcx, span, key, QueryMode::Get).unwrap(),
+        Some(value) => {
+            if tcx.query_system.query_stats {
+                query_cache.stats().record_hit();
+            }
+            value
+        }
+        None => {
+            if tcx.query_system.query_stats {
+                query_cache.stats().record_miss();
+            }
+            execute_query(tcx, span, key, QueryMode::Get).unwrap()
+        }
     }
 }
 
@@ -167,7 +181,14 @@ pub fn query_ensure<'tcx, Cache>(
     Cache: QueryCache,
 {
     let key = key.into_query_param();
-    if try_get_cached(tcx, query_cache, &key).is_none() {
+    if try_get_cached(tcx, query_cache, &key).is_some() {
+        if tcx.query_system.query_stats {
+            query_cache.stats().record_hit();
+        }
+    } else {
+        if tcx.query_system.query_stats {
+            query_cache.stats().record_miss();
+        }
         execute_query(tcx, DUMMY_SP, key, QueryMode::Ensure { check_cache });
     }
 }
@@ -31,6 +31,7 @@
     TyVid, TypeAndMut, Visibility,
 };
 use crate::ty::{GenericArg, GenericArgs, GenericArgsRef};
+use crate::util::common::to_readable_str;
 use rustc_ast::{self as ast, attr};
 use rustc_data_structures::fingerprint::Fingerprint;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
@@ -184,6 +185,59 @@ fn new(arena: &'tcx WorkerLocal<Arena<'tcx>>) -> CtxtInterners<'tcx> {
         }
     }
 
+    /// Returns the number of values held by each interner, along with the number of arena
+    /// bytes they take up. Data owned by the interned values themselves, like the bytes of an
+    /// `Allocation`, is not included.
+    fn stats(&self) -> Vec<(&'static str, usize, usize)> {
+        fn sized<T>(set: &InternedSet<'_, T>) -> (usize, usize) {
+            let len: usize = set.lock_shards().iter().map(|shard| shard.len()).sum();
+            (len, len * mem::size_of::<T>())
+        }
+        fn list<T>(set: &InternedSet<'_, List<T>>) -> (usize, usize) {
+            // The length is followed by the elements, padded to their alignment.
+            let header = mem::size_of::<usize>().max(mem::align_of::<T>());
+            set.lock_shards().iter().flat_map(|shard| shard.keys()).fold(
+                (0, 0),
+                |(len, bytes), &InternedInSet(list)| {
+                    (len + 1, bytes + header + list.len() * mem::size_of::<T>())
+                },
+            )
+        }
+
+        macro_rules! stats {
+            ($($kind:ident($name:ident)),* $(,)?) => {{
+                // Listing every field makes sure that new interners are not left out.
+                let CtxtInterners { arena: _, $($name),* } = self;
+                vec![$({
+                    let (len, bytes) = $kind($name);
+                    (stringify!($name), len, bytes)
+                }),*]
+            }};
+        }
+
+        stats!(
+            sized(type_),
+            list(const_lists),
+            list(args),
+            list(type_lists),
+            list(canonical_var_infos),
+            sized(region),
+            list(poly_existential_predicates),
+            sized(predicate),
+            list(clauses),
+            list(projs),
+            list(place_elems),
+            sized(const_),
+            sized(const_allocation),
+            list(bound_variable_kinds),
+            sized(layout),
+            sized(adt_def),
+            sized(external_constraints),
+            sized(predefined_opaques_in_body),
+            list(fields),
+        )
+    }
+
     /// Interns a type. (Use `mk_*` functions instead, where possible.)
     #[allow(rustc::usage_of_ty_tykind)]
     #[inline(never)]
@@ -1386,6 +1440,62 @@ fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 
         DebugStats(self)
     }
+
+    /// Prints the number of bytes reserved by the type and HIR arenas, followed by the number
+    /// of values held by each interner and the arena bytes they take up. The number of types of
+    /// each kind is logged by `debug_stats` instead.
+    ///
+    /// Interned values are spread over the dropless arena (types, regions, lists, ...) and a
+    /// few typed arenas, so the interner table breaks these arenas down further. With the
+    /// parallel compiler, the arena sizes only cover the arenas of the current thread, while
+    /// the interners are shared by all threads.
+    pub fn print_arena_stats(self) {
+        let prefix = "arena-stats";
+        let arenas =
+            [("ty", self.arena.allocated_bytes()), ("hir", self.hir_arena.allocated_bytes())];
+        let total_bytes: usize =
+            arenas.iter().flat_map(|(_, stats)| stats).map(|&(_, bytes)| bytes).sum();
+
+        eprintln!("{prefix} ARENA STATS");
+        eprintln!("{} {:<35}{:>10}{:>10}", prefix, "Arena", "", "Size");
+        eprintln!("{prefix} ----------------------------------------------------------------");
+        for (arena, stats) in arenas {
+            for (name, bytes) in stats {
+                eprintln!(
+                    "{} {:<35}{:>10}{:>10}",
+                    prefix,
+                    format!("{arena}::{name}"),
+                    "",
+                    to_readable_str(bytes)
+                );
+            }
+        }
+        eprintln!("{prefix} ----------------------------------------------------------------");
+        eprintln!("{} {:<35}{:>10}{:>10}", prefix, "Total", "", to_readable_str(total_bytes));
+        eprintln!("{prefix}");
+
+        let interners = self.interners.stats();
+        eprintln!("{} {:<35}{:>10}{:>10}", prefix, "Interner", "Count", "Size");
+        eprintln!("{prefix} ----------------------------------------------------------------");
+        for &(name, len, bytes) in &interners {
+            eprintln!(
+                "{} {:<35}{:>10}{:>10}",
+                prefix,
+                name,
+                to_readable_str(len),
+                to_readable_str(bytes)
+            );
+        }
+        eprintln!("{prefix} ----------------------------------------------------------------");
+        eprintln!(
+            "{} {:<35}{:>10}{:>10}",
+            prefix,
+            "Total",
+            to_readable_str(interners.iter().map(|&(_, len, _)| len).sum()),
+            to_readable_str(interners.iter().map(|&(_, _, bytes)| bytes).sum())
+        );
+        eprintln!("{prefix}");
+    }
 }
 
 // This type holds a `T` in the interner. The `T` is stored in the arena and
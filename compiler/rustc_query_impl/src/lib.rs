@@ -50,6 +50,9 @@
 mod profiling_support;
 pub use self::profiling_support::alloc_self_profile_query_strings;
 
+mod stats;
+pub use self::stats::print_query_stats;
+
 struct DynamicConfig<
     'tcx,
     C: QueryCache,
@@ -207,6 +210,7 @@ pub fn query_system<'tcx>(
     extern_providers: ExternProviders,
     on_disk_cache: Option<OnDiskCache<'tcx>>,
     incremental: bool,
+    query_stats: bool,
 ) -> QuerySystem<'tcx> {
     QuerySystem {
         states: Default::default(),
@@ -222,6 +226,7 @@ pub fn query_system<'tcx>(
             try_mark_green: try_mark_green,
         },
         jobs: AtomicU64::new(1),
+        query_stats,
     }
 }
 
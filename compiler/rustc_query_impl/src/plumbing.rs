@@ -672,6 +672,10 @@ pub fn alloc_self_profile_query_strings<'tcx>(tcx: TyCtxt<'tcx>, string_cache: &
                 )
             }
 
+            pub fn query_stats<'tcx>(tcx: TyCtxt<'tcx>) -> $crate::stats::QueryStats {
+                $crate::stats::query_stats(stringify!($name), &tcx.query_system.caches.$name)
+            }
+
             item_if_cached! { [$($modifiers)*] {
                 pub fn encode_query_results<'tcx>(
                     tcx: TyCtxt<'tcx>,
@@ -717,6 +721,9 @@ pub fn dynamic_queries<'tcx>() -> DynamicQueries<'tcx> {
             for<'tcx> fn(TyCtxt<'tcx>, &mut QueryKeyStringCache)
         ] = &[$(query_impl::$name::alloc_self_profile_query_strings),*];
 
+        const QUERY_STATS: &[for<'tcx> fn(TyCtxt<'tcx>) -> $crate::stats::QueryStats] =
+            &[$(query_impl::$name::query_stats),*];
+
         const ENCODE_QUERY_RESULTS: &[
             Option<for<'tcx> fn(
                 TyCtxt<'tcx>,
@@ -0,0 +1,63 @@
+use rustc_middle::ty::TyCtxt;
+use rustc_middle::util::common::to_readable_str;
+use rustc_query_system::query::QueryCache;
+use std::cmp::Reverse;
+
+/// How well the in-memory cache of a single query performed.
+pub struct QueryStats {
+    name: &'static str,
+    hits: usize,
+    misses: usize,
+    entries: usize,
+}
+
+pub(crate) fn query_stats<C: QueryCache>(name: &'static str, cache: &C) -> QueryStats {
+    let mut entries = 0;
+    cache.iter(&mut |_, _, _| entries += 1);
+    let (hits, misses) = cache.stats().hits_and_misses();
+    QueryStats { name, hits, misses, entries }
+}
+
+/// Prints the cache hits, misses and entries of every query that was used during the
+/// session, most frequently looked up first. This is enabled by `-Z query-stats`.
+pub fn print_query_stats(tcx: TyCtxt<'_>) {
+    let mut stats: Vec<_> = super::QUERY_STATS
+        .iter()
+        .map(|collect| collect(tcx))
+        .filter(|stats| stats.hits + stats.misses > 0 || stats.entries > 0)
+        .collect();
+    stats.sort_by_key(|stats| (Reverse(stats.hits + stats.misses), Reverse(stats.entries)));
+
+    let prefix = "query-stats";
+    let print_row = |name: &str, hits: usize, misses: usize, entries: usize| {
+        let hit_rate = if hits + misses > 0 {
+            format!("{:.1}%", (hits * 100) as f64 / (hits + misses) as f64)
+        } else {
+            "-".to_string()
+        };
+        eprintln!(
+            "{prefix} {name:<50}{:>12}{:>12}{hit_rate:>10}{:>12}",
+            to_readable_str(hits),
+            to_readable_str(misses),
+            to_readable_str(entries)
+        );
+    };
+
+    eprintln!("{prefix} QUERY STATS");
+    eprintln!(
+        "{} {:<50}{:>12}{:>12}{:>10}{:>12}",
+        prefix, "Query", "Hits", "Misses", "Hit rate", "Entries"
+    );
+    eprintln!("{prefix} {}", "-".repeat(96));
+    for stats in &stats {
+        print_row(stats.name, stats.hits, stats.misses, stats.entries);
+    }
+    eprintln!("{prefix} {}", "-".repeat(96));
+    print_row(
+        "Total",
+        stats.iter().map(|stats| stats.hits).sum(),
+        stats.iter().map(|stats| stats.misses).sum(),
+        stats.iter().map(|stats| stats.entries).sum(),
+    );
+    eprintln!("{prefix}");
+}
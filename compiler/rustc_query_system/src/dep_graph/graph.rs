@@ -10,6 +10,7 @@
 use rustc_serialize::opaque::{FileEncodeResult, FileEncoder};
 use smallvec::{smallvec, SmallVec};
 use std::assert_matches::assert_matches;
+use std::cmp::Reverse;
 use std::collections::hash_map::Entry;
 use std::fmt::Debug;
 use std::hash::Hash;
@@ -917,6 +918,53 @@ fn try_mark_previous_green<Qcx: QueryContext<DepKind = K>>(
         Some(dep_node_index)
     }
 
+    /// Prints, for each kind of node of the previous session, how many of its nodes were
+    /// reused (green), how many were recomputed with a different result (red), and how many
+    /// were not needed in this session.
+    fn print_reuse_info(&self) {
+        if self.previous.node_count() == 0 {
+            return;
+        }
+
+        let mut stats: FxHashMap<K, (usize, usize, usize)> = FxHashMap::default();
+        for prev_index in self.colors.values.indices() {
+            let (green, red, unused) =
+                stats.entry(self.previous.index_to_node(prev_index).kind).or_default();
+            match self.colors.get(prev_index) {
+                Some(DepNodeColor::Green(_)) => *green += 1,
+                Some(DepNodeColor::Red) => *red += 1,
+                None => *unused += 1,
+            }
+        }
+        let mut stats: Vec<_> = stats.into_iter().collect();
+        stats.sort_by_key(|&(_, (green, red, _))| (Reverse(red), Reverse(green)));
+
+        const SEPARATOR: &str = "[incremental] --------------------------------\
+                                 ----------------------------------------------\
+                                 -----------------";
+
+        eprintln!("[incremental] Reuse Statistics");
+        eprintln!("{SEPARATOR}");
+        eprintln!(
+            "[incremental]  {:<36}| {:<17}| {:<17}| {:<17}|",
+            "Node Kind", "Reused (green)", "Changed (red)", "Not needed"
+        );
+        eprintln!("{SEPARATOR}");
+
+        for (kind, (green, red, unused)) in stats {
+            eprintln!(
+                "[incremental]  {:<36}|{:>17} |{:>17} |{:>17} |",
+                format!("{kind:?}"),
+                green,
+                red,
+                unused,
+            );
+        }
+
+        eprintln!("{SEPARATOR}");
+        eprintln!("[incremental]");
+    }
+
     /// Atomically emits some loaded diagnostics.
     /// This may be called concurrently on multiple threads for the same dep node.
     #[cold]
@@ -958,6 +1006,42 @@ pub fn is_green(&self, dep_node: &DepNode<K>) -> bool {
         self.node_color(dep_node).is_some_and(|c| c.is_green())
     }
 
+    /// Explains why `dep_node` could not be marked as green. Marking a node stops at the first
+    /// of its dependencies from the previous session that is not green, so this returns that
+    /// dependency, followed by the first dependency that is not green of that node, and so on
+    /// until reaching an input that changed or a node that was not re-evaluated.
+    pub fn red_dependency_chain<Tcx: DepContext<DepKind = K>>(
+        &self,
+        tcx: Tcx,
+        dep_node: &DepNode<K>,
+    ) -> Vec<DepNode<K>> {
+        let mut chain = Vec::new();
+        let Some(data) = &self.data else { return chain };
+        let Some(mut prev_index) = data.previous.node_to_index_opt(dep_node) else {
+            return chain;
+        };
+
+        while let Some(&dep_index) = data
+            .previous
+            .edge_targets_from(prev_index)
+            .iter()
+            .find(|&&dep_index| !data.is_index_green(dep_index))
+        {
+            let dep_node = data.previous.index_to_node(dep_index);
+            chain.push(dep_node);
+            // The dependencies of eval-always nodes are never checked, and the dependencies of
+            // nodes that were not re-evaluated say nothing about this session.
+            if data.colors.get(dep_index) != Some(DepNodeColor::Red)
+                || tcx.is_eval_always(dep_node.kind)
+            {
+                break;
+            }
+            prev_index = dep_index;
+        }
+
+        chain
+    }
+
     /// This method loads all on-disk cacheable query results into memory, so
     /// they can be written out to the new cache file again. Most query results
     /// will already be in memory but in the case where we marked something as
@@ -990,7 +1074,8 @@ pub fn print_incremental_info(&self) {
             data.current.encoder.borrow().print_incremental_info(
                 data.current.total_read_count.load(Relaxed),
                 data.current.total_duplicate_read_count.load(Relaxed),
-            )
+            );
+            data.print_reuse_info();
         }
     }
 
@@ -2,11 +2,12 @@
 
 use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::sharded::{self, Sharded};
-use rustc_data_structures::sync::Lock;
+use rustc_data_structures::sync::{AtomicUsize, Lock};
 use rustc_index::{Idx, IndexVec};
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::marker::PhantomData;
+use std::sync::atomic::Ordering;
 
 pub trait CacheSelector<'tcx, V> {
     type Cache
@@ -24,6 +25,34 @@ pub trait QueryCache: Sized {
     fn complete(&self, key: Self::Key, value: Self::Value, index: DepNodeIndex);
 
     fn iter(&self, f: &mut dyn FnMut(&Self::Key, &Self::Value, DepNodeIndex));
+
+    fn stats(&self) -> &CacheStats;
+}
+
+/// Counts how many lookups in a query cache were answered by it, for `-Z query-stats`.
+///
+/// The counters are only updated when that option is enabled, see `QuerySystem::query_stats`.
+#[derive(Default)]
+pub struct CacheStats {
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl CacheStats {
+    #[inline]
+    pub fn record_hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn record_miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the number of cache hits and misses.
+    pub fn hits_and_misses(&self) -> (usize, usize) {
+        (self.hits.load(Ordering::Relaxed), self.misses.load(Ordering::Relaxed))
+    }
 }
 
 pub struct DefaultCacheSelector<K>(PhantomData<K>);
@@ -36,11 +65,12 @@ impl<'tcx, K: Eq + Hash, V: 'tcx> CacheSelector<'tcx, V> for DefaultCacheSelecto
 
 pub struct DefaultCache<K, V> {
     cache: Sharded<FxHashMap<K, (V, DepNodeIndex)>>,
+    stats: CacheStats,
 }
 
 impl<K, V> Default for DefaultCache<K, V> {
     fn default() -> Self {
-        DefaultCache { cache: Default::default() }
+        DefaultCache { cache: Default::default(), stats: Default::default() }
     }
 }
 
@@ -77,6 +107,11 @@ fn iter(&self, f: &mut dyn FnMut(&Self::Key, &Self::Value, DepNodeIndex)) {
             }
         }
     }
+
+    #[inline(always)]
+    fn stats(&self) -> &CacheStats {
+        &self.stats
+    }
 }
 
 pub struct SingleCacheSelector;
@@ -89,11 +124,12 @@ impl<'tcx, V: 'tcx> CacheSelector<'tcx, V> for SingleCacheSelector {
 
 pub struct SingleCache<V> {
     cache: Lock<Option<(V, DepNodeIndex)>>,
+    stats: CacheStats,
 }
 
 impl<V> Default for SingleCache<V> {
     fn default() -> Self {
-        SingleCache { cache: Lock::new(None) }
+        SingleCache { cache: Lock::new(None), stats: Default::default() }
     }
 }
 
@@ -119,6 +155,11 @@ fn iter(&self, f: &mut dyn FnMut(&Self::Key, &Self::Value, DepNodeIndex)) {
             f(&(), &value.0, value.1)
         }
     }
+
+    #[inline(always)]
+    fn stats(&self) -> &CacheStats {
+        &self.stats
+    }
 }
 
 pub struct VecCacheSelector<K>(PhantomData<K>);
@@ -131,11 +172,12 @@ impl<'tcx, K: Idx, V: 'tcx> CacheSelector<'tcx, V> for VecCacheSelector<K> {
 
 pub struct VecCache<K: Idx, V> {
     cache: Sharded<IndexVec<K, Option<(V, DepNodeIndex)>>>,
+    stats: CacheStats,
 }
 
 impl<K: Idx, V> Default for VecCache<K, V> {
     fn default() -> Self {
-        VecCache { cache: Default::default() }
+        VecCache { cache: Default::default(), stats: Default::default() }
     }
 }
 
@@ -169,4 +211,9 @@ fn iter(&self, f: &mut dyn FnMut(&Self::Key, &Self::Value, DepNodeIndex)) {
             }
         }
     }
+
+    #[inline(always)]
+    fn stats(&self) -> &CacheStats {
+        &self.stats
+    }
 }
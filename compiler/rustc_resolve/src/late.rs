@@ -2627,9 +2627,10 @@ fn with_generic_param_rib<'c, F>(
         if let LifetimeBinderKind::BareFnType
         | LifetimeBinderKind::WhereBound
         | LifetimeBinderKind::Function
-        | LifetimeBinderKind::ImplBlock = generics_kind
+        | LifetimeBinderKind::ImplBlock
+        | LifetimeBinderKind::PolyTrait = generics_kind
         {
-            self.maybe_report_lifetime_uses(generics_span, params)
+            self.maybe_report_lifetime_uses(generics_span, generics_kind, params)
         }
     }
 
@@ -4513,7 +4514,25 @@ pub(crate) fn late_resolve_crate(&mut self, krate: &Crate) {
         late_resolution_visitor.resolve_doc_links(&krate.attrs, MaybeExported::Ok(CRATE_NODE_ID));
         visit::walk_crate(&mut late_resolution_visitor, krate);
         for (id, span) in late_resolution_visitor.diagnostic_metadata.unused_labels.iter() {
-            self.lint_buffer.buffer_lint(lint::builtin::UNUSED_LABELS, *id, *span, "unused label");
+            // Remove the label together with its `:` and the whitespace that follows it. The
+            // source following a label produced by a macro belongs to the macro rather than to
+            // the label, so only suggest removing labels that were written out by hand.
+            let removal_span = (!span.from_expansion()).then(|| {
+                self.tcx
+                    .sess
+                    .source_map()
+                    .span_extend_while(*span, |c| c == ':' || c.is_whitespace())
+                    .unwrap_or(*span)
+            });
+            self.lint_buffer.buffer_lint_with_diagnostic(
+                lint::builtin::UNUSED_LABELS,
+                *id,
+                *span,
+                "unused label",
+                removal_span.map_or(lint::BuiltinLintDiagnostics::Normal, |removal_span| {
+                    lint::BuiltinLintDiagnostics::UnusedLabel { removal_span }
+                }),
+            );
         }
     }
 }
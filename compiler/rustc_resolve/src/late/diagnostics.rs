@@ -2205,8 +2205,12 @@ pub(crate) fn suggestion_for_label_in_rib(
     pub(crate) fn maybe_report_lifetime_uses(
         &mut self,
         generics_span: Span,
+        binder_kind: LifetimeBinderKind,
         params: &[ast::GenericParam],
     ) {
+        // `T: for<'a> Trait<'a>` is the usual way to spell a higher-ranked bound, so the
+        // binders of trait bounds only get a lint of their own for lifetimes never used.
+        let in_poly_trait = matches!(binder_kind, LifetimeBinderKind::PolyTrait);
         for (param_index, param) in params.iter().enumerate() {
             let GenericParamKind::Lifetime = param.kind else { continue };
 
@@ -2250,6 +2254,7 @@ pub(crate) fn maybe_report_lifetime_uses(
             };
             match use_set {
                 Some(LifetimeUseSet::Many) => {}
+                Some(LifetimeUseSet::One { .. }) if in_poly_trait => {}
                 Some(LifetimeUseSet::One { use_span, use_ctxt }) => {
                     debug!(?param.ident, ?param.ident.span, ?use_span);
 
@@ -2274,8 +2279,13 @@ pub(crate) fn maybe_report_lifetime_uses(
 
                     // if the lifetime originates from expanded code, we won't be able to remove it #104432
                     if deletion_span.is_some_and(|sp| !sp.in_derive_expansion()) {
+                        let lint = if in_poly_trait {
+                            lint::builtin::UNUSED_HIGHER_RANKED_LIFETIMES
+                        } else {
+                            lint::builtin::UNUSED_LIFETIMES
+                        };
                         self.r.lint_buffer.buffer_lint_with_diagnostic(
-                            lint::builtin::UNUSED_LIFETIMES,
+                            lint,
                             param.id,
                             param.ident.span,
                             format!("lifetime parameter `{}` never used", param.ident),
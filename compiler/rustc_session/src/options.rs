@@ -1665,6 +1665,10 @@ pub(crate) fn parse_dump_solver_proof_tree(
         "use a more precise version of drop elaboration for matches on enums (default: yes). \
         This results in better codegen, but has caused miscompilations on some tier 2 platforms. \
         See #77382 and #74551."),
+    print_arena_stats: bool = (false, parse_bool, [UNTRACKED],
+        "print the memory reserved by each arena and the number of values and bytes held by \
+        each interner. With the parallel compiler, only the arenas of the main thread are \
+        counted (default: no)"),
     #[rustc_lint_opt_deny_field_access("use `Session::print_codegen_stats` instead of this field")]
     print_codegen_stats: bool = (false, parse_bool, [UNTRACKED],
         "print codegen statistics (default: no)"),
@@ -1696,6 +1700,9 @@ pub(crate) fn parse_dump_solver_proof_tree(
         "name of the profiler runtime crate to automatically inject (default: `profiler_builtins`)"),
     query_dep_graph: bool = (false, parse_bool, [UNTRACKED],
         "enable queries of the dependency graph for regression testing (default: no)"),
+    query_stats: bool = (false, parse_bool, [UNTRACKED],
+        "print cache hits, misses and entries for each query at the end of the session \
+        (default: no)"),
     randomize_layout: bool = (false, parse_bool, [TRACKED],
         "randomize the layout of types (default: no)"),
     relax_elf_relocations: Option<bool> = (None, parse_opt_bool, [TRACKED],
@@ -0,0 +1,19 @@
+// ignore-tidy-linelength
+// compile-flags: --diagnostic-width=120
+// Labels and source lines are measured in columns rather than bytes or `char`s. Wide characters
+// must not trim a source line that fits, cut too much from the start of a long line, or shift
+// the annotations drawn to the right of a label.
+
+struct 数据;
+struct 类型;
+
+fn f(_: 数据, _: 类型) {}
+
+fn main() {
+    f(类型, /* padding padding padding */ 数据);
+    //~^ ERROR arguments to this function are incorrect
+    let _: () = "这是一个包含很多宽字符的字符串，用来测试诊断信息的右边距是否按照显示宽度而不是字节数计算";
+    //~^ ERROR mismatched types
+    /* 这是一段很长的注释，里面全是宽字符，用来测试当源代码行的开头被截掉时，诊断信息是否按照显示宽度而不是字符数来截取，以免把标注的代码也截掉了 */ let _: () = 42;
+    //~^ ERROR mismatched types
+}
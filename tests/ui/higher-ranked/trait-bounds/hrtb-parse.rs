@@ -6,6 +6,7 @@
 
 #![allow(unused_variables)]
 #![allow(dead_code)]
+#![allow(unused_higher_ranked_lifetimes)]
 
 trait Get<A,R> {
     fn get(&self, arg: A) -> R;
@@ -1,6 +1,8 @@
 // run-pass
 // compile-flags: -Csymbol-mangling-version=v0
 
+#![allow(unused_higher_ranked_lifetimes)]
+
 pub fn f<T: ?Sized>() {}
 pub trait Frob<T: ?Sized> {}
 fn main() {
@@ -0,0 +1,54 @@
+// run-rustfix
+
+#![deny(duplicate_lifetime_bounds)]
+#![allow(dead_code)]
+
+fn same_list<'a: 'b + 'b, 'b>(x: &'a u8) -> &'b u8 {
+    //~^ ERROR duplicate lifetime bound
+    x
+}
+
+fn param_and_where<'a: 'b, 'b>(x: &'a u8) -> &'b u8 where 'a: 'b {
+    //~^ ERROR duplicate lifetime bound
+    x
+}
+
+fn twice<'a, 'b>(x: &'a u8) -> &'b u8 where 'a: 'b, 'a: 'b {
+    //~^ ERROR duplicate lifetime bound
+    x
+}
+
+fn repeated_where<'a, 'b, 'c>(x: &'a u8, _: &'c u8) -> &'b u8
+where
+    'a: 'b,
+    'a: 'c + 'b, //~ ERROR duplicate lifetime bound
+{
+    x
+}
+
+fn first_of_many<'a: 'b, 'b, 'c>(x: &'a u8, _: &'c u8) -> &'b u8
+where
+    'a: 'b + 'c, //~ ERROR duplicate lifetime bound
+{
+    x
+}
+
+fn f<'a: 'b, 'b>() where 'a: 'b, 'a: 'b {}
+//~^ ERROR duplicate lifetime bounds
+
+fn trailing_run<'a: 'b, 'b, 'c>(x: &'a u8, _: &'c u8) -> &'b u8 where 'b: 'c, 'a: 'b, 'a: 'b {
+    //~^ ERROR duplicate lifetime bounds
+    x
+}
+
+fn leading_run<'a: 'b, 'b, 'c>(x: &'a u8, _: &'c u8) -> &'b u8
+where
+    'a: 'b,
+    'a: 'b,
+    'b: 'c,
+{
+    //~^^^^ ERROR duplicate lifetime bounds
+    x
+}
+
+fn main() {}
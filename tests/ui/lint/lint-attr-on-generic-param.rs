@@ -0,0 +1,31 @@
+// check-pass
+// Lint attributes placed on individual generic parameters must be
+// respected both by the lints the resolver buffers for those parameters
+// and by late lints that check them.
+
+#![deny(non_snake_case, non_upper_case_globals)]
+#![deny(single_use_lifetimes, unused_lifetimes)]
+
+fn lifetime<#[allow(non_snake_case)] 'A>(x: &'A u8) -> &'A u8 {
+    x
+}
+
+fn constant<#[allow(non_upper_case_globals)] const n: usize>() -> usize {
+    n
+}
+
+struct Both<#[allow(non_snake_case)] 'A, #[allow(non_upper_case_globals)] const n: usize>(
+    &'A [u8; n],
+);
+
+fn single_use<#[allow(single_use_lifetimes)] 'a>(_: &'a u8) {}
+
+fn unused<#[allow(unused_lifetimes)] 'a>() {}
+
+fn main() {
+    let _ = lifetime(&0);
+    let _ = constant::<3>();
+    let _ = Both::<'static, 0>(&[]);
+    single_use(&0);
+    unused();
+}
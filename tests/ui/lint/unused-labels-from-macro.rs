@@ -0,0 +1,19 @@
+// Labels coming from macro expansions are linted without suggesting to remove them, as the
+// source following such a label belongs to the macro rather than to the label.
+
+// check-pass
+
+#![warn(unused_labels)]
+
+macro_rules! labeled_loop {
+    () => {
+        'unused_from_macro_rules: loop {
+            //~^ WARN unused label
+            break;
+        }
+    };
+}
+
+fn main() {
+    labeled_loop!();
+}
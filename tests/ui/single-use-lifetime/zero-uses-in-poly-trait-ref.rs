@@ -0,0 +1,20 @@
+// run-rustfix
+
+// Test that we DO warn when a lifetime introduced by the `for<>` binder of a
+// trait bound is not used at all.
+
+#![deny(unused_higher_ranked_lifetimes)]
+#![allow(dead_code)]
+
+fn january<T: for<'a> Fn()>(_: T) {}
+//~^ ERROR lifetime parameter `'a` never used
+//~| HELP elide the unused lifetime
+
+fn february<T: for<'a, 'b> Fn(&'a u32)>(_: T) {}
+//~^ ERROR lifetime parameter `'b` never used
+//~| HELP elide the unused lifetime
+
+// A single use is the usual way to write a higher-ranked bound.
+fn march<T: for<'a> Fn(&'a u32)>(_: T) {}
+
+fn main() {}
@@ -4,5 +4,6 @@
 
 type Z = dyn for<'x> Send;
 //~^ WARN type alias `Z` is never used
+//~| WARN lifetime parameter `'x` never used
 
 fn main() {}
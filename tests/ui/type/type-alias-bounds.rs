@@ -1,7 +1,7 @@
 // Test `ignored_generic_bounds` lint warning about bounds in type aliases.
 
 // check-pass
-#![allow(dead_code)]
+#![allow(dead_code, duplicate_lifetime_bounds)]
 
 use std::rc::Rc;
 